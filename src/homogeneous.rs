@@ -1,4 +1,5 @@
 use super::*;
+use std::io::Write;
 
 pub trait TextFormatter {
     fn format_text(&self, source: String) -> String;
@@ -44,6 +45,13 @@ pub struct TableLayout {
     renderer: Renderer,
     row_id: usize,
     print_header: bool,
+    separator_width: usize,
+    max_total_width: Option<usize>,
+    // Each column's upper_bound as configured at construction (e.g. by
+    // fixed_width), kept separately from the live value so repeated fitting
+    // passes always reseed from the user's original intent instead of the
+    // previous pass's shrunk-down output.
+    configured_upper_bounds: Vec<Option<usize>>,
     // cache
     titles: Vec<String>
 }
@@ -72,15 +80,67 @@ impl TableLayout {
             renderer.write_to_layout(row_id, &titles);
         }
 
+        let configured_upper_bounds = renderer.column_configured_upper_bounds(row_id);
+
         TableLayout {
             permutation,
             renderer,
             row_id,
             print_header,
+            separator_width: separator.chars().count(),
+            max_total_width: None,
+            configured_upper_bounds,
             titles
         }
     }
 
+    pub fn set_max_width(&mut self, width: usize) {
+        self.max_total_width = Some(width);
+    }
+
+    pub fn clear_max_width(&mut self) {
+        self.max_total_width = None;
+    }
+
+    // Largest-first fitting pass: shrink the widest columns one cell at a
+    // time, never below their lower_bound, until the row fits max_total_width.
+    // Reseeded from `configured_upper_bounds` (not the live upper_bound) on
+    // every call, so a prior row's shrink never latches a column narrower
+    // than it needs to be for rows that follow.
+    fn fit_columns(&mut self) {
+        let budget = match self.max_total_width {
+            None => return,
+            Some(width) => width
+        };
+
+        let raw_max = self.renderer.column_max_widths(self.row_id);
+        if raw_max.is_empty() {
+            return;
+        }
+        let mut fitted: Vec<usize> = raw_max.iter().zip(self.configured_upper_bounds.iter())
+            .map(|(&max, bound)| match bound {
+                None => max,
+                Some(ub) => (*ub).min(max)
+            })
+            .collect();
+        let lower_bounds = self.renderer.column_lower_bounds(self.row_id);
+        let sep_total = self.separator_width * (fitted.len() - 1);
+
+        while fitted.iter().sum::<usize>() + sep_total > budget {
+            let widest = fitted.iter().enumerate()
+                .filter(|(i, width)| **width > lower_bounds[*i])
+                .max_by_key(|(_, width)| **width)
+                .map(|(i, _)| i);
+
+            match widest {
+                None => break,
+                Some(idx) => fitted[idx] -= 1
+            }
+        }
+
+        self.renderer.set_column_upper_bounds(self.row_id, &fitted);
+    }
+
     pub fn append_data(&mut self, source: impl TableSource) {
         let mut dat = Vec::new();
         for title in self.permutation.iter() {
@@ -95,10 +155,57 @@ impl TableLayout {
     }
 
     pub fn flush(&mut self) -> String {
+        self.fit_columns();
         let ret = self.renderer.flush();
         if self.print_header {
             self.renderer.write_to_layout(self.row_id, &self.titles);
         }
         ret
     }
+
+    // Renders `rows` straight to `sink` without buffering the whole table:
+    // the first `sample_window` rows are held just long enough to resolve
+    // column widths, then every row (sampled or not) is written and dropped
+    // as it is produced.
+    pub fn stream<W: Write>(
+        separator: &str,
+        print_header: bool,
+        layout: Vec<(Title, ColumnLayout)>,
+        sample_window: usize,
+        rows: impl Iterator<Item = impl TableSource>,
+        sink: &mut W
+    ) -> std::io::Result<()> {
+        let mut table = TableLayout::new(separator, print_header, layout);
+        let mut rows = rows;
+
+        for _ in 0..sample_window {
+            match rows.next() {
+                None => break,
+                Some(row) => table.append_data(row)
+            }
+        }
+
+        // Only pin widths once something has actually been measured (a
+        // sampled row or the header); with neither, column_max_widths is all
+        // zero and fix_column_widths would pin every column to an empty
+        // render for the rest of the stream.
+        if sample_window > 0 || print_header {
+            let widths = table.renderer.column_max_widths(table.row_id);
+            table.renderer.fix_column_widths(table.row_id, &widths);
+        }
+
+        table.fit_columns();
+        let head = table.renderer.flush();
+        sink.write_all(head.as_bytes())?;
+
+        for row in rows {
+            table.append_data(row);
+            table.fit_columns();
+            let chunk = table.renderer.flush();
+            sink.write_all(b"\n")?;
+            sink.write_all(chunk.as_bytes())?;
+        }
+
+        Ok(())
+    }
 }
@@ -12,12 +12,21 @@ pub enum Pos {
     Right
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WidthMode {
+    CharCount,
+    DisplayWidth
+}
+
+#[derive(Clone, Debug)]
 pub struct ColumnLayout {
     lower_bound: usize,
     upper_bound: Option<usize>,
     pos: Pos,
-    pad_char: char
+    pad_char: char,
+    width_mode: WidthMode,
+    truncation_suffix: Option<String>,
+    wrap: bool
 }
 
 impl ColumnLayout
@@ -27,6 +36,9 @@ impl ColumnLayout
                      , upper_bound: None
                      , pos
                      , pad_char
+                     , width_mode: WidthMode::CharCount
+                     , truncation_suffix: None
+                     , wrap: false
                      }
     }
 
@@ -35,11 +47,14 @@ impl ColumnLayout
                      , upper_bound: Some(width)
                      , pos: Pos::Right
                      , pad_char
+                     , width_mode: WidthMode::CharCount
+                     , truncation_suffix: None
+                     , wrap: false
                      }
     }
 
     pub fn repeat(&self, count: usize) -> Vec<ColumnLayout> {
-        vec![*self; count]
+        vec![self.clone(); count]
     }
 
     pub fn set_lower_bound(&mut self, lower_bound: usize) {
@@ -62,28 +77,155 @@ impl ColumnLayout
         self.pad_char = pad_char;
     }
 
-    fn render(&self, min: usize, max: usize, value: &str, out: &mut String)
-    {
-        if min > max {
-            panic!("min > max");
+    pub fn set_width_mode(&mut self, width_mode: WidthMode) {
+        self.width_mode = width_mode;
+    }
+
+    pub fn set_truncation_suffix(&mut self, suffix: &str) {
+        self.truncation_suffix = Some(suffix.to_string());
+    }
+
+    pub fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+    }
+
+    // Word-wraps on spaces, falling back to a hard break mid-word when a
+    // single word alone exceeds `width`.
+    fn wrap_lines(&self, value: &str, width: usize) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut current = String::new();
+        let mut current_width = 0;
+
+        for word in value.split(' ') {
+            let word_width = self.measure(word);
+
+            if word_width > width {
+                if !current.is_empty() {
+                    lines.push(std::mem::take(&mut current));
+                }
+                let mut chunk = String::new();
+                let mut chunk_width = 0;
+                let mut chars = word.chars().peekable();
+                while let Some(c) = chars.next() {
+                    // Copy ANSI escape sequences whole so a hard break never
+                    // lands inside one, same skipping as display_width/truncate_to_width.
+                    if c == '\x1b' {
+                        chunk.push(c);
+                        while let Some(&next) = chars.peek() {
+                            chunk.push(next);
+                            chars.next();
+                            if next.is_ascii_alphabetic() {
+                                break;
+                            }
+                        }
+                        continue;
+                    }
+                    let cw = match self.width_mode {
+                        WidthMode::CharCount => 1,
+                        WidthMode::DisplayWidth => char_display_width(c)
+                    };
+                    if chunk_width + cw > width && !chunk.is_empty() {
+                        lines.push(std::mem::take(&mut chunk));
+                        chunk_width = 0;
+                    }
+                    chunk.push(c);
+                    chunk_width += cw;
+                }
+                current = chunk;
+                current_width = chunk_width;
+                continue;
+            }
+
+            let projected = if current.is_empty() { word_width } else { current_width + 1 + word_width };
+            if projected > width && !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+                current_width = 0;
+            }
+
+            if !current.is_empty() {
+                current.push(' ');
+                current_width += 1;
+            }
+            current.push_str(word);
+            current_width += word_width;
+        }
+
+        if !current.is_empty() || lines.is_empty() {
+            lines.push(current);
         }
 
-        let text = TermString::new(value, false).unwrap();
-        let count = text.clone().visible_chars_count();
+        lines
+    }
+
+    fn measure(&self, value: &str) -> usize {
+        match self.width_mode {
+            WidthMode::CharCount => TermString::new(value, false).unwrap().visible_chars_count(),
+            WidthMode::DisplayWidth => display_width(value)
+        }
+    }
+
+    // (truncation bound, final padded width) a cell tracking `max` resolves
+    // to in this column. Pad target is derived from the truncation bound
+    // (not the raw observed length) so a truncated wide row never inflates
+    // the pad target past what any row, including itself, ever prints.
+    fn resolved_bounds(&self, max: usize) -> (usize, usize) {
         let adjusted_upper_bound = match self.upper_bound {
             None => max,
             Some(ub) => ub.min(max)
         };
-        let adjusted_lower_bound = self.lower_bound.max(max);
+        let adjusted_lower_bound = self.lower_bound.max(adjusted_upper_bound);
+        (adjusted_upper_bound, adjusted_lower_bound)
+    }
 
-        let truncated = if count > adjusted_upper_bound {
-            text.truncated(adjusted_upper_bound)
-        } else {
-            text.truncated(count)
+    // The width this column actually renders at for a cell tracking `max`.
+    fn resolved_width(&self, max: usize) -> usize {
+        self.resolved_bounds(max).1
+    }
+
+    fn render(&self, min: usize, max: usize, value: &str, out: &mut String)
+    {
+        if min > max {
+            panic!("min > max");
+        }
+
+        let count = self.measure(value);
+        let (adjusted_upper_bound, adjusted_lower_bound) = self.resolved_bounds(max);
+
+        let (rendered, rendered_width) = match self.width_mode {
+            WidthMode::CharCount => {
+                if count > adjusted_upper_bound {
+                    let suffix = self.truncation_suffix.as_deref().unwrap_or("");
+                    let suffix_width = suffix.chars().count();
+                    let text_bound = adjusted_upper_bound.saturating_sub(suffix_width);
+                    let text = TermString::new(value, false).unwrap();
+                    let truncated = text.truncated(text_bound);
+                    let mut rendered = truncated.as_str().to_string();
+                    rendered.push_str(suffix);
+                    let width = text_bound.min(count) + suffix_width;
+                    (rendered, width)
+                } else {
+                    let text = TermString::new(value, false).unwrap();
+                    let truncated = text.truncated(count);
+                    (truncated.as_str().to_string(), count)
+                }
+            }
+            WidthMode::DisplayWidth => {
+                if count > adjusted_upper_bound {
+                    let suffix = self.truncation_suffix.as_deref().unwrap_or("");
+                    let suffix_width = display_width(suffix);
+                    let text_bound = adjusted_upper_bound.saturating_sub(suffix_width);
+                    let mut rendered = truncate_to_width(value, text_bound);
+                    let width = display_width(&rendered) + suffix_width;
+                    rendered.push_str(suffix);
+                    (rendered, width)
+                } else {
+                    (value.to_string(), count)
+                }
+            }
         };
 
-        let pads_needed = if adjusted_lower_bound > count {
-            adjusted_lower_bound - count
+        let pads_needed = if adjusted_lower_bound > rendered_width {
+            adjusted_lower_bound - rendered_width
         } else {
             0
         };
@@ -93,10 +235,10 @@ impl ColumnLayout
                 for _ in 0..pads_needed {
                     out.push(self.pad_char);
                 }
-                out.push_str(truncated.as_str())
+                out.push_str(rendered.as_str())
             }
             Pos::Left => {
-                out.push_str(truncated.as_str());
+                out.push_str(rendered.as_str());
                 for _ in 0..pads_needed {
                     out.push(self.pad_char);
                 }
@@ -106,7 +248,7 @@ impl ColumnLayout
                 for _ in 0..pad_count {
                     out.push(self.pad_char);
                 }
-                out.push_str(truncated.as_str());
+                out.push_str(rendered.as_str());
                 for _ in 0..pad_count {
                     out.push(self.pad_char);
                 }
@@ -118,6 +260,84 @@ impl ColumnLayout
     }
 }
 
+// Per Unicode East Asian Width (UAX #11): wide/fullwidth scalars occupy two
+// terminal cells, combining marks occupy none, everything else occupies one.
+fn is_combining_mark(c: char) -> bool {
+    matches!(c as u32,
+        0x0300..=0x036F | 0x1AB0..=0x1AFF | 0x1DC0..=0x1DFF |
+        0x20D0..=0x20FF | 0xFE20..=0xFE2F)
+}
+
+fn is_wide(c: char) -> bool {
+    matches!(c as u32,
+        0x1100..=0x115F |
+        0x2E80..=0x303E |
+        0x3041..=0x33FF |
+        0x3400..=0x4DBF |
+        0x4E00..=0x9FFF |
+        0xA000..=0xA4CF |
+        0xAC00..=0xD7A3 |
+        0xF900..=0xFAFF |
+        0xFE30..=0xFE4F |
+        0xFF00..=0xFF60 |
+        0xFFE0..=0xFFE6 |
+        0x1F300..=0x1FAFF |
+        0x20000..=0x3FFFD)
+}
+
+fn char_display_width(c: char) -> usize {
+    if is_combining_mark(c) {
+        0
+    } else if is_wide(c) {
+        2
+    } else {
+        1
+    }
+}
+
+fn display_width(value: &str) -> usize {
+    let mut width = 0;
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            for next in chars.by_ref() {
+                if next.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            continue;
+        }
+        width += char_display_width(c);
+    }
+    width
+}
+
+fn truncate_to_width(value: &str, max_width: usize) -> String {
+    let mut out = String::new();
+    let mut width = 0;
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            out.push(c);
+            while let Some(&next) = chars.peek() {
+                out.push(next);
+                chars.next();
+                if next.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            continue;
+        }
+        let w = char_display_width(c);
+        if width + w > max_width {
+            break;
+        }
+        width += w;
+        out.push(c);
+    }
+    out
+}
+
 #[derive(Clone, Debug)]
 pub struct Column {
     layout: ColumnLayout,
@@ -184,7 +404,7 @@ impl RowLayout {
         self.columns.push(Column::new(column));
     }
     pub fn extend_column_layouts(&mut self, columns: &[ColumnLayout]) {
-        let cols: Vec<_> = columns.iter().map(|c| Column::new(*c)).collect();
+        let cols: Vec<_> = columns.iter().map(|c| Column::new(c.clone())).collect();
         self.columns.extend(cols)
     }
     pub fn reset(&mut self) {
@@ -196,12 +416,137 @@ impl RowLayout {
     }
 }
 
+#[derive(Copy, Clone, Debug)]
+pub struct BorderStyle {
+    pub top_left: char,
+    pub top_sep: char,
+    pub top_right: char,
+    pub top_fill: char,
+    pub mid_left: char,
+    pub mid_sep: char,
+    pub mid_right: char,
+    pub mid_fill: char,
+    pub bottom_left: char,
+    pub bottom_sep: char,
+    pub bottom_right: char,
+    pub bottom_fill: char,
+    pub vertical: char
+}
+
+impl BorderStyle {
+    pub fn ascii() -> BorderStyle {
+        BorderStyle { top_left: '+', top_sep: '+', top_right: '+', top_fill: '-'
+                    , mid_left: '+', mid_sep: '+', mid_right: '+', mid_fill: '-'
+                    , bottom_left: '+', bottom_sep: '+', bottom_right: '+', bottom_fill: '-'
+                    , vertical: '|'
+                    }
+    }
+
+    pub fn rounded() -> BorderStyle {
+        BorderStyle { top_left: '╭', top_sep: '┬', top_right: '╮', top_fill: '─'
+                    , mid_left: '├', mid_sep: '┼', mid_right: '┤', mid_fill: '─'
+                    , bottom_left: '╰', bottom_sep: '┴', bottom_right: '╯', bottom_fill: '─'
+                    , vertical: '│'
+                    }
+    }
+
+    pub fn heavy() -> BorderStyle {
+        BorderStyle { top_left: '┏', top_sep: '┳', top_right: '┓', top_fill: '━'
+                    , mid_left: '┣', mid_sep: '╋', mid_right: '┫', mid_fill: '━'
+                    , bottom_left: '┗', bottom_sep: '┻', bottom_right: '┛', bottom_fill: '━'
+                    , vertical: '┃'
+                    }
+    }
+
+    pub fn none() -> BorderStyle {
+        BorderStyle { top_left: ' ', top_sep: ' ', top_right: ' ', top_fill: ' '
+                    , mid_left: ' ', mid_sep: ' ', mid_right: ' ', mid_fill: ' '
+                    , bottom_left: ' ', bottom_sep: ' ', bottom_right: ' ', bottom_fill: ' '
+                    , vertical: ' '
+                    }
+    }
+
+    fn rule(&self, left: char, sep: char, right: char, fill: char, widths: &[usize]) -> String {
+        let mut out = String::new();
+        out.push(left);
+        for (i, width) in widths.iter().enumerate() {
+            if i > 0 {
+                out.push(sep);
+            }
+            for _ in 0..*width {
+                out.push(fill);
+            }
+        }
+        out.push(right);
+        out
+    }
+
+    fn top_rule(&self, widths: &[usize]) -> String {
+        self.rule(self.top_left, self.top_sep, self.top_right, self.top_fill, widths)
+    }
+
+    fn mid_rule(&self, widths: &[usize]) -> String {
+        self.rule(self.mid_left, self.mid_sep, self.mid_right, self.mid_fill, widths)
+    }
+
+    fn bottom_rule(&self, widths: &[usize]) -> String {
+        self.rule(self.bottom_left, self.bottom_sep, self.bottom_right, self.bottom_fill, widths)
+    }
+
+    // Points a row's start/sep/end tokens at this style's vertical glyph, so
+    // the cell dividers line up with the junctions drawn by the horizontal
+    // rules above and below.
+    pub fn apply_to(&self, row: &mut RowLayout) {
+        let vertical = self.vertical.to_string();
+        row.set_start_token(vertical.clone());
+        row.set_separator(vertical.clone());
+        row.set_end_token(vertical);
+    }
+}
+
+// Expands one logical row into its synthesized physical lines: columns with
+// wrap enabled whose content exceeds their truncation bound are split across
+// several lines, other columns render blank on the continuation lines.
+fn build_row_lines(def: &RowLayout, cells: &[String]) -> Vec<String> {
+    let wrapped: Vec<Vec<String>> = def.columns.iter().zip(cells.iter()).map(|(column, cell)| {
+        let col = column.borrow();
+        let (bound, _) = col.layout.resolved_bounds(col.max);
+        if col.layout.wrap && bound > 0 && col.layout.measure(cell) > bound {
+            col.layout.wrap_lines(cell, bound)
+        } else {
+            vec![cell.clone()]
+        }
+    }).collect();
+
+    let height = wrapped.iter().map(|lines| lines.len()).max().unwrap_or(1).max(1);
+
+    (0..height).map(|line_idx| {
+        let mut line = String::new();
+        line.push_str(def.start.as_str());
+        let mut once = false;
+        for (column, lines) in def.columns.iter().zip(wrapped.iter()) {
+            if once {
+                line.push_str(def.sep.as_str());
+            } else {
+                once = true;
+            }
+            let piece = lines.get(line_idx).map(|s| s.as_str()).unwrap_or("");
+            column.borrow().render(piece, &mut line);
+        }
+        line.push_str(def.end.as_str());
+        line
+    }).collect()
+}
+
 pub struct Renderer {
     rules: Vec<(RowLayout, Vec<VecDeque<String>>)>,
     newline: String,
     begin: String,
     end: String,
-    write_logs: VecDeque<usize>
+    write_logs: VecDeque<usize>,
+    border: Option<BorderStyle>,
+    rule_after_header: bool,
+    rule_between_rows: bool
 }
 
 impl Default for Renderer {
@@ -211,6 +556,9 @@ impl Default for Renderer {
                  , begin: "".to_string()
                  , end: "".to_string()
                  , write_logs: VecDeque::new()
+                 , border: None
+                 , rule_after_header: false
+                 , rule_between_rows: false
                  }
     }
 }
@@ -221,7 +569,10 @@ impl Renderer {
                  , newline: "\n".to_string()
                  , begin: "".to_string()
                  , end: "".to_string()
-                 , write_logs: VecDeque::new() 
+                 , write_logs: VecDeque::new()
+                 , border: None
+                 , rule_after_header: false
+                 , rule_between_rows: false
                  }
     }
 
@@ -237,6 +588,70 @@ impl Renderer {
         self.end = end;
     }
 
+    pub fn set_border(&mut self, border: BorderStyle) {
+        self.border = Some(border);
+    }
+
+    pub fn clear_border(&mut self) {
+        self.border = None;
+    }
+
+    pub fn set_rule_after_header(&mut self, flag: bool) {
+        self.rule_after_header = flag;
+    }
+
+    pub fn set_rule_between_rows(&mut self, flag: bool) {
+        self.rule_between_rows = flag;
+    }
+
+    pub(crate) fn column_max_widths(&self, layout: usize) -> Vec<usize> {
+        match self.rules.get(layout) {
+            None => Vec::new(),
+            Some((def, _)) => def.columns.iter().map(|col| col.borrow().max).collect()
+        }
+    }
+
+    // Each column's current upper_bound. Read before any fitting pass (as
+    // TableLayout::new does), this is the bound as configured (e.g. by
+    // ColumnLayout::fixed_width); set_column_upper_bounds and
+    // fix_column_widths overwrite it. Callers that re-derive a fitting seed
+    // every pass keep that early reading, to avoid latching a column to a
+    // stale, narrower width forever.
+    pub(crate) fn column_configured_upper_bounds(&self, layout: usize) -> Vec<Option<usize>> {
+        match self.rules.get(layout) {
+            None => Vec::new(),
+            Some((def, _)) => def.columns.iter().map(|col| col.borrow().layout.upper_bound).collect()
+        }
+    }
+
+    pub(crate) fn column_lower_bounds(&self, layout: usize) -> Vec<usize> {
+        match self.rules.get(layout) {
+            None => Vec::new(),
+            Some((def, _)) => def.columns.iter().map(|col| col.borrow().layout.lower_bound).collect()
+        }
+    }
+
+    pub(crate) fn set_column_upper_bounds(&mut self, layout: usize, bounds: &[usize]) {
+        if let Some((def, _)) = self.rules.get_mut(layout) {
+            for (col, bound) in def.columns.iter().zip(bounds.iter()) {
+                col.borrow_mut().layout.set_upper_bound(*bound);
+            }
+        }
+    }
+
+    // Pins a column to a fixed width regardless of future writes, so a row
+    // rendered in isolation (as in a streaming pass) still lines up with
+    // widths resolved from an earlier sampling pass.
+    pub(crate) fn fix_column_widths(&mut self, layout: usize, widths: &[usize]) {
+        if let Some((def, _)) = self.rules.get_mut(layout) {
+            for (col, width) in def.columns.iter().zip(widths.iter()) {
+                let mut col = col.borrow_mut();
+                col.layout.set_lower_bound(*width);
+                col.layout.set_upper_bound(*width);
+            }
+        }
+    }
+
     pub fn register_layout(&mut self, layout: RowLayout) -> usize {
         let new_id = self.rules.len();
         let count  = &layout.columns.len();
@@ -254,9 +669,8 @@ impl Renderer {
                 let dat  = data.iter();
 
                 for (col, (col_dat, dat)) in cols.zip(col_dat.zip(dat)) {
-                    let text = TermString::new(dat, false).unwrap();
-                    let text_len = text.visible_chars_count();
                     let mut col = col.borrow_mut();
+                    let text_len = col.layout.measure(dat);
                     col.min = std::cmp::min(col.min, text_len);
                     col.max = std::cmp::max(col.max, text_len);
                     col_dat.push_back(dat.to_string());
@@ -269,28 +683,70 @@ impl Renderer {
     pub fn flush(&mut self) -> String {
         let mut buf = String::new();
         let mut not_first_line = false;
+
+        let mut remaining: std::collections::HashMap<usize, usize> = std::collections::HashMap::new();
+        for idx in self.write_logs.iter() {
+            *remaining.entry(*idx).or_insert(0) += 1;
+        }
+        let mut emitted: std::collections::HashMap<usize, usize> = std::collections::HashMap::new();
+
         while let Some(rule_idx) = self.write_logs.pop_front() {
             if not_first_line {
                 buf.push_str(self.newline.as_str());
             } else {
                 not_first_line = true;
             }
-            let (def, cols_dat) = self.rules.get_mut(rule_idx).unwrap();
-            let mut once = false;
-            buf.push_str(def.start.as_str());
-            // Iterate the state and definition together
-            let zipped = cols_dat.iter_mut().zip(def.columns.iter());
-            // For each column in the row
-            for (deque, col) in zipped {
-                if once {
-                    buf.push_str(def.sep.as_str());
-                } else {
-                    once = true;
+
+            let widths: Vec<usize> = match &self.border {
+                None => Vec::new(),
+                Some(_) => {
+                    let (def, _) = self.rules.get(rule_idx).unwrap();
+                    def.columns.iter()
+                        .map(|col| {
+                            let col = col.borrow();
+                            col.layout.resolved_width(col.max)
+                        })
+                        .collect()
                 }
+            };
+
+            let seen_before = *emitted.get(&rule_idx).unwrap_or(&0);
+            if let Some(border) = &self.border {
+                if seen_before == 0 {
+                    buf.push_str(&border.top_rule(&widths));
+                    buf.push_str(self.newline.as_str());
+                }
+            }
 
-                col.borrow().render(&deque.pop_front().expect(""), &mut buf);
+            let (def, cols_dat) = self.rules.get_mut(rule_idx).unwrap();
+            let cells: Vec<String> = cols_dat.iter_mut()
+                .map(|deque| deque.pop_front().expect(""))
+                .collect();
+            let mut lines = build_row_lines(def, &cells).into_iter();
+            if let Some(first) = lines.next() {
+                buf.push_str(&first);
+            }
+            for continuation in lines {
+                buf.push_str(self.newline.as_str());
+                buf.push_str(&continuation);
+            }
+
+            *emitted.entry(rule_idx).or_insert(0) += 1;
+            let left = remaining.get_mut(&rule_idx).unwrap();
+            *left -= 1;
+
+            if let Some(border) = &self.border {
+                let emitted_count = emitted[&rule_idx];
+                if *left == 0 {
+                    buf.push_str(self.newline.as_str());
+                    buf.push_str(&border.bottom_rule(&widths));
+                } else if (emitted_count == 1 && self.rule_after_header)
+                    || (emitted_count > 1 && self.rule_between_rows)
+                {
+                    buf.push_str(self.newline.as_str());
+                    buf.push_str(&border.mid_rule(&widths));
+                }
             }
-            buf.push_str(def.end.as_str());
         }
 
         for (row, _) in self.rules.iter_mut() {
@@ -316,7 +772,7 @@ mod tests {
         row1.push_column(&col0);
         row1.set_separator("|".to_string());
         row1.push_column_layout(col1);
-        row1.push_column_layout(col3);
+        row1.push_column_layout(col3.clone());
 
         let mut row2 = RowLayout::new();
         row2.push_column(&col0);
@@ -347,7 +803,7 @@ mod tests {
         let mut row1 = RowLayout::new();
         row1.set_separator("|".to_string());
         row1.push_column_layout(col1);
-        row1.push_column_layout(col3);
+        row1.push_column_layout(col3.clone());
 
         let mut row2 = RowLayout::new();
         row2.set_separator("|".to_string());
@@ -375,6 +831,9 @@ mod tests {
             , upper_bound: None
             , pos: Pos::Left
             , pad_char: ' '
+            , width_mode: WidthMode::CharCount
+            , truncation_suffix: None
+            , wrap: false
             };
 
         {
@@ -391,6 +850,9 @@ mod tests {
             , upper_bound: None
             , pos: Pos::Middle
             , pad_char: ' '
+            , width_mode: WidthMode::CharCount
+            , truncation_suffix: None
+            , wrap: false
             };
 
         {
@@ -419,6 +881,154 @@ mod tests {
             "12345     1 \x1b[93m12345\x1b[0m\n    1   123      ");
     }
 
+    #[test]
+    fn test_truncation_suffix_aligns_with_untruncated_rows() {
+        let mut col = ColumnLayout::align(Pos::Left, ' ');
+        col.set_upper_bound(5);
+        col.set_truncation_suffix("...");
+
+        let mut row = RowLayout::new();
+        row.push_column_layout(col);
+
+        let mut renderer = Renderer::new();
+        let handle = renderer.register_layout(row);
+        renderer.write_to_layout(handle, &["HelloWorld".to_string()]);
+        renderer.write_to_layout(handle, &["Hi".to_string()]);
+
+        let output = renderer.flush();
+        assert_eq!(output.as_str(), "He...\nHi   ");
+    }
+
+    #[test]
+    fn test_border_matches_fixed_width_column() {
+        let col = ColumnLayout::fixed_width(10, ' ');
+        let mut row = RowLayout::new();
+        row.push_column_layout(col);
+
+        let mut renderer = Renderer::new();
+        renderer.set_border(BorderStyle::ascii());
+        let handle = renderer.register_layout(row);
+        renderer.write_to_layout(handle, &["Hi".to_string()]);
+
+        let output = renderer.flush();
+        assert_eq!(output.as_str(),
+            "+----------+\n        Hi\n+----------+");
+    }
+
+    #[test]
+    fn test_fit_columns_preserves_fixed_width_bound() {
+        struct Row(String);
+        impl homogeneous::TableSource for Row {
+            fn value_for_column(&self, column: &str) -> Option<String> {
+                match column {
+                    "x" => Some(self.0.clone()),
+                    _ => None
+                }
+            }
+        }
+
+        let mut table = homogeneous::TableLayout::new(
+            " | ",
+            false,
+            vec![(homogeneous::Title::new("X", "x"), ColumnLayout::fixed_width(5, ' '))]
+        );
+        // A budget far above the row's actual width must not loosen a
+        // column's pre-set fixed_width bound.
+        table.set_max_width(200);
+        table.append_data(Row("HelloWorldThisIsLong".to_string()));
+
+        let output = table.flush();
+        assert_eq!(output.as_str(), "Hello");
+    }
+
+    #[test]
+    fn test_fit_columns_does_not_latch_shrink_across_rows() {
+        struct Row(String, String);
+        impl homogeneous::TableSource for Row {
+            fn value_for_column(&self, column: &str) -> Option<String> {
+                match column {
+                    "a" => Some(self.0.clone()),
+                    "b" => Some(self.1.clone()),
+                    _ => None
+                }
+            }
+        }
+
+        let mut table = homogeneous::TableLayout::new(
+            " | ",
+            false,
+            vec![(homogeneous::Title::new("A", "a"), ColumnLayout::align(Pos::Left, ' ')),
+                 (homogeneous::Title::new("B", "b"), ColumnLayout::align(Pos::Left, ' '))]
+        );
+        table.set_max_width(10);
+
+        // Forces the oversized column "a" to shrink to fit the budget; the
+        // same pass also sets column "b"'s upper bound to its width of 1.
+        table.append_data(Row("a".repeat(20), "b".to_string()));
+        table.flush();
+
+        // A later row that fits comfortably on its own must not still be
+        // clamped by the bounds the previous row's pass set.
+        table.append_data(Row("hi".to_string(), "world".to_string()));
+        let output = table.flush();
+        assert_eq!(output.as_str(), "hi | world");
+    }
+
+    #[test]
+    fn test_flush_wraps_one_column_and_blanks_others_on_continuation() {
+        let mut wrapped_col = ColumnLayout::align(Pos::Left, ' ');
+        wrapped_col.set_upper_bound(5);
+        wrapped_col.set_wrap(true);
+        let plain_col = ColumnLayout::align(Pos::Left, ' ');
+
+        let mut row = RowLayout::new();
+        row.set_separator("|".to_string());
+        row.push_column_layout(wrapped_col);
+        row.push_column_layout(plain_col);
+
+        let mut renderer = Renderer::new();
+        let handle = renderer.register_layout(row);
+        renderer.write_to_layout(handle, &["hello world".to_string(), "x".to_string()]);
+
+        let output = renderer.flush();
+        assert_eq!(output.as_str(), "hello|x\nworld| ");
+    }
+
+    #[test]
+    fn test_wrap_lines_keeps_ansi_escapes_intact() {
+        let mut col = ColumnLayout::align(Pos::Left, ' ');
+        col.set_width_mode(WidthMode::DisplayWidth);
+
+        let lines = col.wrap_lines("\x1b[31mabcdefghij\x1b[0m", 8);
+
+        assert_eq!(lines, vec!["\x1b[31mabcdefgh".to_string(), "ij\x1b[0m".to_string()]);
+    }
+
+    #[test]
+    fn test_stream_with_zero_sample_window_keeps_cell_content() {
+        struct Row(&'static str, &'static str);
+        impl homogeneous::TableSource for Row {
+            fn value_for_column(&self, column: &str) -> Option<String> {
+                match column {
+                    "a" => Some(self.0.to_string()),
+                    "b" => Some(self.1.to_string()),
+                    _ => None
+                }
+            }
+        }
+
+        let layout = vec![
+            (homogeneous::Title::new("A", "a"), ColumnLayout::align(Pos::Left, ' ')),
+            (homogeneous::Title::new("B", "b"), ColumnLayout::align(Pos::Left, ' '))
+        ];
+        let rows = vec![Row("hi", "world"), Row("foo", "bar")].into_iter();
+
+        let mut out = Vec::new();
+        homogeneous::TableLayout::stream(" | ", false, layout, 0, rows, &mut out).unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), "\nhi | world\nfoo | bar");
+    }
+
     #[test]
     fn test_rows_rendering() {
         let unbound_col = ColumnLayout::align(Pos::Left, ' ');